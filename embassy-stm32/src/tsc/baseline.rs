@@ -0,0 +1,104 @@
+//! Automatic baseline tracking and drift compensation for a TSC channel.
+
+/// Maximum number of consecutive samples a channel may sit far from its baseline before the
+/// baseline is forcibly re-centered on the current reading.
+const STUCK_RECALIBRATE_SAMPLES: u32 = 200;
+
+/// Tracks a slow-moving reference ("baseline") of the untouched TSC count for one channel and
+/// reports touch as a signed delta from it, rather than relying on an absolute threshold.
+///
+/// The baseline follows an exponential/IIR update, `baseline += (raw - baseline) >> shift`,
+/// applied only while no touch is detected so a held finger isn't slowly absorbed into the
+/// baseline. If a channel is stuck far from its baseline for too long (e.g. after power-on with
+/// a finger already resting on it), the baseline is snapped to the current reading instead.
+pub struct BaselineTracker {
+    baseline: u16,
+    shift: u8,
+    delta: i32,
+    touched: bool,
+    stuck_samples: u32,
+}
+
+impl BaselineTracker {
+    pub fn new(initial_value: u16, shift: u8) -> Self {
+        Self {
+            baseline: initial_value,
+            shift,
+            delta: 0,
+            touched: false,
+            stuck_samples: 0,
+        }
+    }
+
+    /// Feed a new raw reading, update the baseline, and return the current signed delta
+    /// (`baseline - raw`). A positive delta means the raw count dropped below baseline, which is
+    /// how a TSC channel behaves when touched.
+    pub fn update(&mut self, raw: u16, detect_delta: u16) -> i32 {
+        self.delta = self.baseline as i32 - raw as i32;
+        self.touched = self.delta >= detect_delta as i32;
+
+        if !self.touched {
+            self.baseline = (self.baseline as i32 + ((raw as i32 - self.baseline as i32) >> self.shift)) as u16;
+            self.stuck_samples = 0;
+        } else {
+            self.stuck_samples += 1;
+            if self.stuck_samples >= STUCK_RECALIBRATE_SAMPLES {
+                self.recalibrate(raw);
+            }
+        }
+
+        self.delta
+    }
+
+    /// Signed delta (`baseline - raw`) computed by the most recent [`update`](Self::update) call.
+    pub fn detect_delta(&self) -> i32 {
+        self.delta
+    }
+
+    /// Forcibly snap the baseline to `raw`, discarding drift history. Useful on startup or when
+    /// a channel has been stuck in a touched state for longer than is physically plausible.
+    pub fn recalibrate(&mut self, raw: u16) {
+        self.baseline = raw;
+        self.stuck_samples = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_drifts_towards_untouched_readings() {
+        let mut tracker = BaselineTracker::new(1000, 2);
+        for _ in 0..50 {
+            tracker.update(900, 100);
+        }
+        assert!(tracker.detect_delta().abs() < 10, "baseline should settle near 900");
+    }
+
+    #[test]
+    fn detects_touch_as_large_negative_drop() {
+        let mut tracker = BaselineTracker::new(1000, 4);
+        let delta = tracker.update(800, 100);
+        assert_eq!(delta, 200);
+    }
+
+    #[test]
+    fn baseline_freezes_while_touched() {
+        let mut tracker = BaselineTracker::new(1000, 2);
+        tracker.update(800, 100); // touch detected, delta = 200 >= 100
+        let baseline_after_first_touch = tracker.baseline;
+        tracker.update(800, 100);
+        assert_eq!(tracker.baseline, baseline_after_first_touch);
+    }
+
+    #[test]
+    fn stuck_channel_recalibrates_after_enough_samples() {
+        let mut tracker = BaselineTracker::new(1000, 2);
+        for _ in 0..STUCK_RECALIBRATE_SAMPLES {
+            tracker.update(800, 100);
+        }
+        // Once recalibrated, the same reading should no longer register as a touch.
+        assert!(tracker.update(800, 100) < 100);
+    }
+}
@@ -0,0 +1,118 @@
+//! Debounced, hysteresis-protected touch button built on top of raw TSC channel readings.
+
+use embassy_time::{Duration, Instant};
+
+use super::Error;
+
+/// Stable press/release state reported by [`TouchButton`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, defmt::Format)]
+pub enum ButtonState {
+    Pressed,
+    Released,
+}
+
+/// A debounced touch button layered on top of a raw TSC channel reading.
+///
+/// `press_threshold` and `release_threshold` form a hysteresis band: the raw value must fall
+/// below `press_threshold` to start a press and rise above `release_threshold` to start a
+/// release. A candidate state change only becomes the reported [`ButtonState`] once the raw
+/// value has stayed on the new side of its threshold for `debounce` continuously.
+pub struct TouchButton {
+    press_threshold: u16,
+    release_threshold: u16,
+    debounce: Duration,
+    state: ButtonState,
+    candidate: ButtonState,
+    candidate_since: Instant,
+}
+
+impl TouchButton {
+    /// Returns `Err(Error::InvalidConfig)` unless `press_threshold < release_threshold`.
+    pub fn new(press_threshold: u16, release_threshold: u16, debounce: Duration) -> Result<Self, Error> {
+        if press_threshold >= release_threshold {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(Self {
+            press_threshold,
+            release_threshold,
+            debounce,
+            state: ButtonState::Released,
+            candidate: ButtonState::Released,
+            candidate_since: Instant::now(),
+        })
+    }
+
+    /// Feed a new raw sensor reading and the time it was taken at, returning the debounced state.
+    pub fn poll(&mut self, raw_value: u16, now: Instant) -> ButtonState {
+        let instantaneous = if raw_value < self.press_threshold {
+            ButtonState::Pressed
+        } else if raw_value > self.release_threshold {
+            ButtonState::Released
+        } else {
+            // Inside the hysteresis band: not past either threshold, so hold the reported state
+            // (this also restarts the debounce window of any pending change).
+            self.state
+        };
+
+        if instantaneous != self.candidate {
+            self.candidate = instantaneous;
+            self.candidate_since = now;
+        } else if self.candidate != self.state && now - self.candidate_since >= self.debounce {
+            self.state = self.candidate;
+        }
+
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(ms: u64) -> Instant {
+        Instant::from_millis(ms)
+    }
+
+    #[test]
+    fn rejects_thresholds_without_hysteresis_band() {
+        assert_eq!(TouchButton::new(30, 30, Duration::from_millis(10)).err(), Some(Error::InvalidConfig));
+        assert!(TouchButton::new(25, 30, Duration::from_millis(10)).is_ok());
+    }
+
+    #[test]
+    fn press_requires_debounce_below_threshold() {
+        let mut button = TouchButton::new(25, 30, Duration::from_millis(10)).unwrap();
+        assert_eq!(button.poll(20, t(0)), ButtonState::Released);
+        assert_eq!(button.poll(20, t(5)), ButtonState::Released);
+        assert_eq!(button.poll(20, t(11)), ButtonState::Pressed);
+    }
+
+    #[test]
+    fn hysteresis_band_does_not_chatter_between_thresholds() {
+        let mut button = TouchButton::new(25, 30, Duration::from_millis(10)).unwrap();
+        assert_eq!(button.poll(20, t(0)), ButtonState::Released);
+        assert_eq!(button.poll(20, t(11)), ButtonState::Pressed);
+        // A reading inside the hysteresis band should not start releasing the button.
+        assert_eq!(button.poll(27, t(12)), ButtonState::Pressed);
+        assert_eq!(button.poll(27, t(30)), ButtonState::Pressed);
+    }
+
+    #[test]
+    fn release_requires_debounce_above_threshold() {
+        let mut button = TouchButton::new(25, 30, Duration::from_millis(10)).unwrap();
+        button.poll(20, t(0));
+        assert_eq!(button.poll(20, t(11)), ButtonState::Pressed);
+        assert_eq!(button.poll(35, t(11)), ButtonState::Pressed);
+        assert_eq!(button.poll(35, t(22)), ButtonState::Released);
+    }
+
+    #[test]
+    fn candidate_reset_restarts_debounce_window() {
+        let mut button = TouchButton::new(25, 30, Duration::from_millis(10)).unwrap();
+        button.poll(20, t(0));
+        // Bounces back above the release threshold before the debounce window elapses.
+        button.poll(35, t(5));
+        assert_eq!(button.poll(20, t(15)), ButtonState::Released);
+        assert_eq!(button.poll(20, t(25)), ButtonState::Pressed);
+    }
+}
@@ -0,0 +1,498 @@
+//! Touch Sensing Controller (TSC) driver.
+//!
+//! Supports blocking acquisition (poll `group_get_status`/`group_get_value` after `start`) as
+//! well as interrupt-driven async acquisition (`Tsc::new` + `acquire().await`) for applications
+//! that don't want to busy-wait for the end-of-acquisition flag.
+
+pub mod baseline;
+pub mod button;
+pub mod timing;
+
+use core::future::poll_fn;
+use core::marker::PhantomData;
+use core::ops::BitOr;
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::task::Poll;
+
+use embassy_hal_internal::interrupt::InterruptExt;
+use embassy_sync::waitqueue::AtomicWaker;
+
+use crate::interrupt;
+use crate::interrupt::typelevel::Interrupt;
+use crate::mode::{Async, Blocking, Mode};
+use crate::Peripheral;
+
+/// TSC error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, defmt::Format)]
+pub enum Error {
+    /// A previous acquisition is still in progress.
+    Busy,
+    /// The configuration is invalid (e.g. no groups enabled).
+    InvalidConfig,
+    /// The max count value was reached before the acquisition completed.
+    MaxCountError,
+}
+
+/// Overall controller state, as reported by [`Tsc::get_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, defmt::Format)]
+pub enum State {
+    Ready,
+    Busy,
+}
+
+/// Per-group acquisition status, as reported by [`Tsc::group_get_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, defmt::Format)]
+pub enum GroupStatus {
+    Ongoing,
+    Complete,
+}
+
+/// One of the TSC's hardware IO groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, defmt::Format)]
+pub enum Group {
+    One,
+    Two,
+    Three,
+    Four,
+}
+
+/// A single TSC-capable IO, identified by its group and IO number within that group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, defmt::Format)]
+pub struct IOPin {
+    pub(crate) group: Group,
+    pub(crate) io: u8,
+}
+
+impl IOPin {
+    pub fn group(&self) -> Group {
+        self.group
+    }
+}
+
+/// Bitmask of active channel IOs, built up with `|` from one or more [`IOPin`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelMask(pub(crate) u32);
+
+impl From<IOPin> for ChannelMask {
+    fn from(pin: IOPin) -> Self {
+        let group_shift = match pin.group {
+            Group::One => 0,
+            Group::Two => 4,
+            Group::Three => 8,
+            Group::Four => 12,
+        };
+        // `pin.io` is 1-based (set_io1..set_io4), but IOCCR/IOSCR channel bits are 0-indexed
+        // per group (G1_IO1 is bit 0, ..., G1_IO4 is bit 3, G2_IO1 is bit 4, ...).
+        ChannelMask(1 << (group_shift + (pin.io - 1) as u32))
+    }
+}
+
+impl BitOr for ChannelMask {
+    type Output = ChannelMask;
+    fn bitor(self, rhs: ChannelMask) -> ChannelMask {
+        ChannelMask(self.0 | rhs.0)
+    }
+}
+
+/// Marker types identifying the role a pin within a group plays: sampling capacitor or sensed
+/// channel.
+pub mod pin_roles {
+    pub struct Sample;
+    pub struct Channel;
+}
+
+/// Group marker types (`G1`..`G4`) used to keep [`PinGroup`]/[`PinGroupWithRoles`] tied to a
+/// specific hardware group at compile time.
+pub struct G1;
+pub struct G2;
+pub struct G3;
+pub struct G4;
+
+pub(crate) trait GroupMarker {
+    const GROUP: Group;
+}
+impl GroupMarker for G1 {
+    const GROUP: Group = Group::One;
+}
+impl GroupMarker for G2 {
+    const GROUP: Group = Group::Two;
+}
+impl GroupMarker for G3 {
+    const GROUP: Group = Group::Three;
+}
+impl GroupMarker for G4 {
+    const GROUP: Group = Group::Four;
+}
+
+/// A pin bound to a role within a [`PinGroupWithRoles`], returned by `set_io1`..`set_io4`.
+#[derive(Clone, Copy)]
+pub struct RolePin<Role> {
+    pub pin: IOPin,
+    _role: PhantomData<Role>,
+}
+
+/// The IOs configured for one hardware group, ready to be placed into a [`PinGroups`].
+#[derive(Clone, Copy, Default)]
+pub struct PinGroup<G> {
+    _group: PhantomData<G>,
+}
+
+/// Builder for configuring the sample/channel roles of the IOs within a single hardware group.
+pub struct PinGroupWithRoles<T, G> {
+    pub pin_group: PinGroup<G>,
+    _instance: PhantomData<T>,
+}
+
+impl<T: Instance, G: GroupMarker> Default for PinGroupWithRoles<T, G> {
+    fn default() -> Self {
+        Self {
+            pin_group: PinGroup::default(),
+            _instance: PhantomData,
+        }
+    }
+}
+
+impl<T: Instance, G: GroupMarker> PinGroupWithRoles<T, G> {
+    fn make_pin(&self, io: u8) -> IOPin {
+        IOPin { group: G::GROUP, io }
+    }
+
+    pub fn set_io1<Role>(&mut self, _pin: impl Peripheral<P = impl crate::gpio::Pin> + 'static) -> RolePin<Role> {
+        RolePin {
+            pin: self.make_pin(1),
+            _role: PhantomData,
+        }
+    }
+
+    pub fn set_io2<Role>(&mut self, _pin: impl Peripheral<P = impl crate::gpio::Pin> + 'static) -> RolePin<Role> {
+        RolePin {
+            pin: self.make_pin(2),
+            _role: PhantomData,
+        }
+    }
+
+    pub fn set_io3<Role>(&mut self, _pin: impl Peripheral<P = impl crate::gpio::Pin> + 'static) -> RolePin<Role> {
+        RolePin {
+            pin: self.make_pin(3),
+            _role: PhantomData,
+        }
+    }
+
+    pub fn set_io4<Role>(&mut self, _pin: impl Peripheral<P = impl crate::gpio::Pin> + 'static) -> RolePin<Role> {
+        RolePin {
+            pin: self.make_pin(4),
+            _role: PhantomData,
+        }
+    }
+}
+
+/// The set of hardware groups enabled for an acquisition.
+#[derive(Clone, Copy, Default)]
+pub struct PinGroups<T> {
+    pub g1: Option<PinGroup<G1>>,
+    pub g2: Option<PinGroup<G2>>,
+    pub g3: Option<PinGroup<G3>>,
+    pub g4: Option<PinGroup<G4>>,
+    _instance: PhantomData<T>,
+}
+
+/// Number of charge-transfer pulses per high/low half-cycle.
+#[derive(Debug, Clone, Copy, defmt::Format)]
+pub enum ChargeTransferPulseCycle {
+    _1,
+    _2,
+    _4,
+    _8,
+    _16,
+}
+
+/// TSC pulse generator prescaler divider.
+#[derive(Debug, Clone, Copy, defmt::Format)]
+pub enum PGPrescalerDivider {
+    _1,
+    _2,
+    _4,
+    _8,
+    _16,
+}
+
+/// Maximum count value before the max-count-error flag is raised.
+#[derive(Debug, Clone, Copy, defmt::Format)]
+pub enum MaxCount {
+    _255,
+    _511,
+    _1023,
+    _8191,
+    _16383,
+}
+
+/// Spread-spectrum deviation, 0..=127.
+#[derive(Debug, Clone, Copy, defmt::Format)]
+pub struct SSDeviation(u8);
+
+impl SSDeviation {
+    pub fn new(value: u8) -> Option<Self> {
+        if value <= 127 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+}
+
+/// TSC peripheral configuration.
+#[derive(Clone, Copy)]
+pub struct Config {
+    pub ct_pulse_high_length: ChargeTransferPulseCycle,
+    pub ct_pulse_low_length: ChargeTransferPulseCycle,
+    pub spread_spectrum: bool,
+    pub spread_spectrum_deviation: SSDeviation,
+    pub spread_spectrum_prescaler: bool,
+    pub pulse_generator_prescaler: PGPrescalerDivider,
+    pub max_count_value: MaxCount,
+    pub io_default_mode: bool,
+    pub synchro_pin_polarity: bool,
+    pub acquisition_mode: bool,
+    pub max_count_interrupt: bool,
+}
+
+/// Acquisition completion state, shared between the ISR and [`Tsc::acquire`].
+struct IrqState {
+    waker: AtomicWaker,
+    result: AtomicU8,
+}
+
+const PENDING: u8 = 0;
+const COMPLETE: u8 = 1;
+const ERROR: u8 = 2;
+
+static STATE: IrqState = IrqState {
+    waker: AtomicWaker::new(),
+    result: AtomicU8::new(PENDING),
+};
+
+/// Interrupt handler for the TSC end-of-acquisition / max-count-error interrupt.
+///
+/// Bind with `bind_interrupts!` and pass to [`Tsc::new`]. Clears `ISR.EOAF`/`ISR.MCEF` via
+/// `ICR`, disables the corresponding `IER` enable bits so the interrupt doesn't keep firing, and
+/// wakes the task waiting in [`Tsc::acquire`].
+pub struct InterruptHandler<T: Instance> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Instance> interrupt::typelevel::Handler<T::Interrupt> for InterruptHandler<T> {
+    unsafe fn on_interrupt() {
+        let regs = T::regs();
+        let isr = regs.isr().read();
+
+        let mut outcome = None;
+        if isr.eoaf() {
+            regs.icr().write(|w| w.set_eoaic(true));
+            outcome.get_or_insert(COMPLETE);
+        }
+        if isr.mcef() {
+            regs.icr().write(|w| w.set_mcec(true));
+            outcome = Some(ERROR);
+        }
+
+        if let Some(result) = outcome {
+            regs.ier().modify(|w| {
+                w.set_eoaie(false);
+                w.set_mceie(false);
+            });
+            STATE.result.store(result, Ordering::Release);
+            STATE.waker.wake();
+        }
+    }
+}
+
+/// TSC driver instance.
+pub struct Tsc<'d, T: Instance, M: Mode> {
+    _peri: PhantomData<&'d mut T>,
+    active_mask: ChannelMask,
+    groups: PinGroups<T>,
+    _mode: PhantomData<M>,
+}
+
+impl<'d, T: Instance> Tsc<'d, T, Blocking> {
+    /// Create a new TSC driver that is polled manually with [`Tsc::poll_for_acquisition`].
+    pub fn new_blocking(
+        peri: impl Peripheral<P = T> + 'd,
+        pin_groups: PinGroups<T>,
+        config: Config,
+    ) -> Result<Self, Error> {
+        let _ = peri;
+        T::configure(&config)?;
+        Ok(Self {
+            _peri: PhantomData,
+            active_mask: ChannelMask::default(),
+            groups: pin_groups,
+            _mode: PhantomData,
+        })
+    }
+}
+
+impl<'d, T: Instance> Tsc<'d, T, Async> {
+    /// Create a new TSC driver backed by the end-of-acquisition interrupt, so acquisitions can be
+    /// awaited with [`Tsc::acquire`] instead of busy-polled.
+    pub fn new(
+        peri: impl Peripheral<P = T> + 'd,
+        pin_groups: PinGroups<T>,
+        config: Config,
+        _irqs: impl interrupt::typelevel::Binding<T::Interrupt, InterruptHandler<T>> + 'd,
+    ) -> Result<Self, Error> {
+        let _ = peri;
+        T::configure(&config)?;
+        T::Interrupt::unpend();
+        unsafe { T::Interrupt::enable() };
+        Ok(Self {
+            _peri: PhantomData,
+            active_mask: ChannelMask::default(),
+            groups: pin_groups,
+            _mode: PhantomData,
+        })
+    }
+
+    /// Start an acquisition, enable the end-of-acquisition (and max-count-error) interrupt, and
+    /// wait for the ISR to signal completion.
+    pub async fn acquire(&mut self) -> Result<(), Error> {
+        STATE.result.store(PENDING, Ordering::Release);
+        self.start();
+
+        let regs = T::regs();
+        regs.ier().modify(|w| {
+            w.set_eoaie(true);
+            w.set_mceie(true);
+        });
+
+        poll_fn(|cx| {
+            STATE.waker.register(cx.waker());
+            match STATE.result.load(Ordering::Acquire) {
+                PENDING => Poll::Pending,
+                COMPLETE => Poll::Ready(Ok(())),
+                _ => Poll::Ready(Err(Error::MaxCountError)),
+            }
+        })
+        .await
+    }
+}
+
+impl<'d, T: Instance, M: Mode> Tsc<'d, T, M> {
+    /// Current controller state.
+    pub fn get_state(&self) -> State {
+        if T::regs().cr().read().start() {
+            State::Busy
+        } else {
+            State::Ready
+        }
+    }
+
+    /// Select which channel IOs are sensed on the next acquisition.
+    pub fn set_active_channels_mask(&mut self, mask: ChannelMask) {
+        self.active_mask = mask;
+        T::regs().ioccr().write(|w| w.0 = mask.0);
+        T::regs().iogcsr().write(|w| w.0 = self.groups_mask());
+    }
+
+    fn groups_mask(&self) -> u32 {
+        let mut mask = 0;
+        if self.groups.g1.is_some() {
+            mask |= 1 << 0;
+        }
+        if self.groups.g2.is_some() {
+            mask |= 1 << 1;
+        }
+        if self.groups.g3.is_some() {
+            mask |= 1 << 2;
+        }
+        if self.groups.g4.is_some() {
+            mask |= 1 << 3;
+        }
+        mask
+    }
+
+    /// Start a single acquisition cycle. Completion is observed by polling
+    /// [`Tsc::group_get_status`] (or, in [`Async`] mode, by calling [`Tsc::acquire`] instead).
+    pub fn start(&mut self) {
+        T::regs().cr().modify(|w| w.set_start(true));
+    }
+
+    /// Busy-poll until the hardware reports the acquisition is no longer running.
+    pub fn poll_for_acquisition(&mut self) {
+        while T::regs().cr().read().start() {}
+    }
+
+    /// Enable or disable IO discharge after an acquisition, to reset the sampling capacitors
+    /// before the next scan.
+    pub fn discharge_io(&mut self, enable: bool) {
+        T::regs().cr().modify(|w| w.set_iodef(!enable));
+    }
+
+    /// Status of one group's acquisition.
+    pub fn group_get_status(&self, group: Group) -> GroupStatus {
+        if T::regs().isr().read().group_complete(group) {
+            GroupStatus::Complete
+        } else {
+            GroupStatus::Ongoing
+        }
+    }
+
+    /// Final count value for a completed group.
+    pub fn group_get_value(&self, group: Group) -> u16 {
+        T::regs().iog_cr(group_index(group)).read().cnt()
+    }
+
+    /// Run one acquisition cycle covering every group configured in [`PinGroups`], blocking until
+    /// the hardware reports completion (equivalent to `start` immediately followed by
+    /// `poll_for_acquisition`). Enables every configured group and re-applies the channel mask
+    /// last set with [`Tsc::set_active_channels_mask`]; it does not select channel IOs itself, so
+    /// only the channels in that mask are sensed. Only once this returns is it safe to discharge
+    /// the IOs — the acquisition is guaranteed to have finished.
+    pub fn acquire_all(&mut self) {
+        T::regs().ioccr().write(|w| w.0 = self.active_mask.0);
+        T::regs().iogcsr().write(|w| w.0 = self.groups_mask());
+        self.start();
+        self.poll_for_acquisition();
+    }
+
+    /// Every enabled group whose status is [`GroupStatus::Complete`], with its value, after an
+    /// [`Tsc::acquire_all`] cycle.
+    pub fn read_all_groups(&self) -> impl Iterator<Item = (Group, u16)> + '_ {
+        [Group::One, Group::Two, Group::Three, Group::Four]
+            .into_iter()
+            .filter(|g| self.group_enabled(*g))
+            .filter_map(|g| match self.group_get_status(g) {
+                GroupStatus::Complete => Some((g, self.group_get_value(g))),
+                GroupStatus::Ongoing => None,
+            })
+    }
+
+    fn group_enabled(&self, group: Group) -> bool {
+        match group {
+            Group::One => self.groups.g1.is_some(),
+            Group::Two => self.groups.g2.is_some(),
+            Group::Three => self.groups.g3.is_some(),
+            Group::Four => self.groups.g4.is_some(),
+        }
+    }
+}
+
+fn group_index(group: Group) -> usize {
+    match group {
+        Group::One => 0,
+        Group::Two => 1,
+        Group::Three => 2,
+        Group::Four => 3,
+    }
+}
+
+trait SealedInstance {
+    fn regs() -> crate::pac::tsc::Tsc;
+    fn configure(config: &Config) -> Result<(), Error>;
+}
+
+/// Implemented by TSC peripheral singletons (e.g. `peripherals::TSC`).
+#[allow(private_bounds)]
+pub trait Instance: SealedInstance + crate::Peripheral<P = Self> + 'static {
+    type Interrupt: interrupt::typelevel::Interrupt;
+}
@@ -0,0 +1,102 @@
+//! Self-tuning discharge delay helper for TSC acquisition loops.
+
+use super::Error;
+
+/// Adjusts a TSC discharge delay based on how many poll attempts the previous acquisition needed.
+///
+/// If an acquisition is still `Ongoing` after the first poll, the delay was too short for the
+/// sampling capacitor to discharge in time, so it's nudged up by `step_ms`. If an acquisition
+/// completes on the very first poll, the delay is nudged back down by `step_ms` to keep scan
+/// time low. The delay is always kept within `[min_ms, max_ms]`.
+pub struct AdaptiveDischargeDelay {
+    delay_ms: u64,
+    min_ms: u64,
+    max_ms: u64,
+    step_ms: u64,
+}
+
+impl AdaptiveDischargeDelay {
+    /// Returns `Err(Error::InvalidConfig)` unless `min_ms <= initial_ms <= max_ms`.
+    pub fn new(initial_ms: u64, min_ms: u64, max_ms: u64, step_ms: u64) -> Result<Self, Error> {
+        if !(min_ms <= initial_ms && initial_ms <= max_ms) {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(Self {
+            delay_ms: initial_ms,
+            min_ms,
+            max_ms,
+            step_ms,
+        })
+    }
+
+    /// Current discharge delay to wait for, in milliseconds.
+    pub fn delay_ms(&self) -> u64 {
+        self.delay_ms
+    }
+
+    /// Record how many poll attempts the last acquisition needed (1 if it completed on the
+    /// first poll) and adjust the delay accordingly.
+    pub fn record_poll_attempts(&mut self, attempts: usize) {
+        if attempts > 1 {
+            self.delay_ms = (self.delay_ms + self.step_ms).min(self.max_ms);
+        } else {
+            self.delay_ms = self.delay_ms.saturating_sub(self.step_ms).max(self.min_ms);
+        }
+    }
+
+    /// Reset to `initial_ms`, discarding any adaptation. Returns `Err(Error::InvalidConfig)`
+    /// unless `initial_ms` is within the bounds this delay was constructed with.
+    pub fn reset(&mut self, initial_ms: u64) -> Result<(), Error> {
+        if !(self.min_ms <= initial_ms && initial_ms <= self.max_ms) {
+            return Err(Error::InvalidConfig);
+        }
+        self.delay_ms = initial_ms;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_initial_value_outside_bounds() {
+        assert_eq!(AdaptiveDischargeDelay::new(25, 1, 20, 1).err(), Some(Error::InvalidConfig));
+        assert!(AdaptiveDischargeDelay::new(5, 1, 20, 1).is_ok());
+    }
+
+    #[test]
+    fn nudges_up_when_more_than_one_attempt_needed() {
+        let mut delay = AdaptiveDischargeDelay::new(5, 1, 20, 1).unwrap();
+        delay.record_poll_attempts(3);
+        assert_eq!(delay.delay_ms(), 6);
+    }
+
+    #[test]
+    fn nudges_down_when_first_attempt_succeeds() {
+        let mut delay = AdaptiveDischargeDelay::new(5, 1, 20, 1).unwrap();
+        delay.record_poll_attempts(1);
+        assert_eq!(delay.delay_ms(), 4);
+    }
+
+    #[test]
+    fn clamps_to_max_and_min_bounds() {
+        let mut delay = AdaptiveDischargeDelay::new(5, 1, 20, 10).unwrap();
+        delay.record_poll_attempts(2);
+        assert_eq!(delay.delay_ms(), 15);
+        delay.record_poll_attempts(2);
+        assert_eq!(delay.delay_ms(), 20);
+
+        let mut delay = AdaptiveDischargeDelay::new(5, 1, 20, 10).unwrap();
+        delay.record_poll_attempts(1);
+        assert_eq!(delay.delay_ms(), 1);
+    }
+
+    #[test]
+    fn reset_rejects_value_outside_bounds() {
+        let mut delay = AdaptiveDischargeDelay::new(5, 1, 20, 1).unwrap();
+        assert_eq!(delay.reset(25), Err(Error::InvalidConfig));
+        assert!(delay.reset(10).is_ok());
+        assert_eq!(delay.delay_ms(), 10);
+    }
+}
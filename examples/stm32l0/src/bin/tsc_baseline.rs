@@ -0,0 +1,123 @@
+// Example of a self-calibrating TSC touch sensor that lights an LED when touch is detected.
+//
+// This example demonstrates:
+// 1. Configuring a single TSC channel pin
+// 2. Tracking a slow-moving baseline of the untouched raw count instead of comparing against a
+//    fixed absolute threshold
+// 3. Reporting a signed delta (baseline - raw) so sensitivity adapts to overlay thickness,
+//    temperature and humidity instead of requiring per-board recalibration
+//
+// Suggested physical setup on STM32L073RZ Nucleo board:
+// - Connect a 1000pF capacitor between pin PA0 and GND. This is your sampling capacitor.
+// - Connect one end of a 1K resistor to pin PA1 and leave the other end loose.
+//   The loose end will act as the touch sensor which will register your touch.
+//
+// The example uses two pins from Group 1 of the TSC on the STM32L073RZ Nucleo board:
+// - PA0 as the sampling capacitor, TSC group 1 IO1 (label A0)
+// - PA1 as the channel pin, TSC group 1 IO2 (label A1)
+
+#![no_std]
+#![no_main]
+
+use defmt::*;
+use embassy_stm32::gpio::{Level, Output, Speed};
+use embassy_stm32::tsc::baseline::BaselineTracker;
+use embassy_stm32::tsc::{self, *};
+use embassy_stm32::{mode, peripherals};
+use embassy_time::Timer;
+use {defmt_rtt as _, panic_probe as _};
+
+const DETECT_DELTA: u16 = 15;
+
+#[embassy_executor::main]
+async fn main(_spawner: embassy_executor::Spawner) {
+    let device_config = embassy_stm32::Config::default();
+    let context = embassy_stm32::init(device_config);
+
+    let tsc_conf = Config {
+        ct_pulse_high_length: ChargeTransferPulseCycle::_4,
+        ct_pulse_low_length: ChargeTransferPulseCycle::_4,
+        spread_spectrum: false,
+        spread_spectrum_deviation: SSDeviation::new(2).unwrap(),
+        spread_spectrum_prescaler: false,
+        pulse_generator_prescaler: PGPrescalerDivider::_16,
+        max_count_value: MaxCount::_255,
+        io_default_mode: false,
+        synchro_pin_polarity: false,
+        acquisition_mode: false,
+        max_count_interrupt: false,
+    };
+
+    let mut g1: PinGroupWithRoles<peripherals::TSC, G1> = PinGroupWithRoles::default();
+    g1.set_io1::<tsc::pin_roles::Sample>(context.PA0);
+    let tsc_sensor = g1.set_io2::<tsc::pin_roles::Channel>(context.PA1);
+
+    let pin_groups: PinGroups<peripherals::TSC> = PinGroups {
+        g1: Some(g1.pin_group),
+        ..Default::default()
+    };
+
+    let mut touch_controller = tsc::Tsc::new_blocking(context.TSC, pin_groups, tsc_conf).unwrap();
+
+    if touch_controller.get_state() != State::Ready {
+        crate::panic!("TSC not ready!");
+    }
+    info!("TSC initialized successfully");
+
+    let mut led = Output::new(context.PA5, Level::High, Speed::Low);
+
+    let discharge_delay = 5; // ms
+    let polling_interval = 10; // ms
+
+    // Take an initial reading to seed the baseline instead of guessing an absolute value.
+    touch_controller.set_active_channels_mask(tsc_sensor.pin.into());
+    touch_controller.start();
+    touch_controller.poll_for_acquisition();
+    touch_controller.discharge_io(true);
+    Timer::after_millis(discharge_delay).await;
+    let seed = read_touch_value(&mut touch_controller, tsc_sensor.pin)
+        .await
+        .unwrap_or(0);
+    let mut baseline = BaselineTracker::new(seed, 4);
+
+    info!("polling for touch");
+    loop {
+        touch_controller.set_active_channels_mask(tsc_sensor.pin.into());
+        touch_controller.start();
+        touch_controller.poll_for_acquisition();
+        touch_controller.discharge_io(true);
+        Timer::after_millis(discharge_delay).await;
+
+        if let Some(raw_value) = read_touch_value(&mut touch_controller, tsc_sensor.pin).await {
+            let delta = baseline.update(raw_value, DETECT_DELTA);
+            info!("sensor delta {}", delta);
+            if delta >= DETECT_DELTA as i32 {
+                led.set_high();
+            } else {
+                led.set_low();
+            }
+        }
+
+        Timer::after_millis(polling_interval).await;
+    }
+}
+
+const MAX_GROUP_STATUS_READ_ATTEMPTS: usize = 10;
+
+// attempt to read group status and delay when still ongoing
+async fn read_touch_value(
+    touch_controller: &mut tsc::Tsc<'_, peripherals::TSC, mode::Blocking>,
+    sensor_pin: tsc::IOPin,
+) -> Option<u16> {
+    for _ in 0..MAX_GROUP_STATUS_READ_ATTEMPTS {
+        match touch_controller.group_get_status(sensor_pin.group()) {
+            GroupStatus::Complete => {
+                return Some(touch_controller.group_get_value(sensor_pin.group()));
+            }
+            GroupStatus::Ongoing => {
+                Timer::after_millis(1).await;
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,168 @@
+// Example of matrix-style scanning with the TSC to address many keys from a handful of group
+// pins.
+//
+// This example demonstrates:
+// 1. Describing a matrix of row/column intersections built from two TSC groups' channel pins
+// 2. Sequencing one acquisition per intersection with a `MatrixScanner`
+// 3. Collecting the results into a 2-D grid of values indexed by (row, col)
+//
+// Note: the STM32 TSC peripheral only measures self-capacitance, it has no hardware support for
+// genuine mutual-capacitance (cross-coupling) sensing. `MatrixScanner` approximates a row/col
+// matrix by activating one row pin and one column pin together and summing their two independent
+// self-capacitance readings. That sum still changes with which row is active, which is enough to
+// resolve a key in practice, but it is not a real mutual-capacitance measurement — don't expect
+// the noise immunity or crosstalk behavior of true mutual-cap touch controllers.
+//
+// This sits alongside the single self-capacitance pad setups in the other `tsc_*` examples:
+// rather than wiring one sensor per group pin, rows and columns of a keypad are crossed so that
+// N group pins can address up to N*M keys.
+//
+// Suggested physical setup on STM32L073RZ Nucleo board:
+// - Row 0 is PA1 (TSC group 1 channel pin, sampling capacitor on PA0).
+// - Row 1 is PC1 (TSC group 3 channel pin, sampling capacitor on PC0), not PA2/PA3: those are
+//   used for the ST-Link VCP UART on this board (see `tsc_blocking.rs`) and will silently not
+//   work as TSC inputs.
+// - Col 0 and col 1 are PB1/PB2 (TSC group 2 channel pins, sampling capacitor on PB0).
+
+#![no_std]
+#![no_main]
+
+use defmt::*;
+use embassy_stm32::gpio::{Level, Output, Speed};
+use embassy_stm32::tsc::{self, *};
+use embassy_stm32::{mode, peripherals};
+use embassy_time::Timer;
+use {defmt_rtt as _, panic_probe as _};
+
+const KEY_THRESHOLD: u16 = 25;
+const ROWS: usize = 2;
+const COLS: usize = 2;
+
+/// Sequences TSC acquisitions over a matrix of row/column channel pin intersections and returns
+/// a 2-D grid of raw values, one per (row, col) key.
+///
+/// Each row pin is scanned against every column pin in turn: only that single pair of channel
+/// pins is made active for the acquisition. The TSC hardware has no genuine mutual-capacitance
+/// mode, so this is a self-capacitance-sum heuristic, not a real cross-coupling measurement: the
+/// two groups' independent self-capacitance readings are summed so the result at least depends
+/// on which row was concurrently active. Reading just the column's own group would ignore the row
+/// entirely and return the same value for every row at that column.
+pub struct MatrixScanner<const ROWS: usize, const COLS: usize> {
+    rows: [tsc::IOPin; ROWS],
+    cols: [tsc::IOPin; COLS],
+}
+
+impl<const ROWS: usize, const COLS: usize> MatrixScanner<ROWS, COLS> {
+    pub fn new(rows: [tsc::IOPin; ROWS], cols: [tsc::IOPin; COLS]) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Scan every intersection and return the raw value for each (row, col) key.
+    pub async fn scan(
+        &self,
+        touch_controller: &mut tsc::Tsc<'_, peripherals::TSC, mode::Blocking>,
+        discharge_delay_ms: u64,
+    ) -> [[u16; COLS]; ROWS] {
+        let mut grid = [[0u16; COLS]; ROWS];
+        for (row_idx, &row_pin) in self.rows.iter().enumerate() {
+            for (col_idx, &col_pin) in self.cols.iter().enumerate() {
+                touch_controller.set_active_channels_mask(ChannelMask::from(row_pin) | ChannelMask::from(col_pin));
+                touch_controller.start();
+                touch_controller.poll_for_acquisition();
+                touch_controller.discharge_io(true);
+                Timer::after_millis(discharge_delay_ms).await;
+
+                grid[row_idx][col_idx] = match (
+                    touch_controller.group_get_status(row_pin.group()),
+                    touch_controller.group_get_status(col_pin.group()),
+                ) {
+                    (GroupStatus::Complete, GroupStatus::Complete) => {
+                        let row_value = touch_controller.group_get_value(row_pin.group());
+                        let col_value = touch_controller.group_get_value(col_pin.group());
+                        // Combine both groups' readings so the result depends on which row was
+                        // concurrently active, not just on the column's own group.
+                        row_value.saturating_add(col_value)
+                    }
+                    _ => u16::MAX,
+                };
+            }
+        }
+        grid
+    }
+}
+
+#[embassy_executor::main]
+async fn main(_spawner: embassy_executor::Spawner) {
+    let device_config = embassy_stm32::Config::default();
+    let context = embassy_stm32::init(device_config);
+
+    let tsc_conf = Config {
+        ct_pulse_high_length: ChargeTransferPulseCycle::_4,
+        ct_pulse_low_length: ChargeTransferPulseCycle::_4,
+        spread_spectrum: false,
+        spread_spectrum_deviation: SSDeviation::new(2).unwrap(),
+        spread_spectrum_prescaler: false,
+        pulse_generator_prescaler: PGPrescalerDivider::_16,
+        max_count_value: MaxCount::_255,
+        io_default_mode: false,
+        synchro_pin_polarity: false,
+        acquisition_mode: false,
+        max_count_interrupt: false,
+    };
+
+    let mut g1: PinGroupWithRoles<peripherals::TSC, G1> = PinGroupWithRoles::default();
+    g1.set_io1::<tsc::pin_roles::Sample>(context.PA0);
+    let row_0 = g1.set_io2::<tsc::pin_roles::Channel>(context.PA1);
+
+    // Row 1 lives in its own group rather than sharing group 1's IO3 (PA2), which is reserved for
+    // the ST-Link VCP UART on this board.
+    let mut g3: PinGroupWithRoles<peripherals::TSC, G3> = PinGroupWithRoles::default();
+    g3.set_io1::<tsc::pin_roles::Sample>(context.PC0);
+    let row_1 = g3.set_io2::<tsc::pin_roles::Channel>(context.PC1);
+
+    let mut g2: PinGroupWithRoles<peripherals::TSC, G2> = PinGroupWithRoles::default();
+    g2.set_io1::<tsc::pin_roles::Sample>(context.PB0);
+    let col_0 = g2.set_io2::<tsc::pin_roles::Channel>(context.PB1);
+    let col_1 = g2.set_io3::<tsc::pin_roles::Channel>(context.PB2);
+
+    let pin_groups: PinGroups<peripherals::TSC> = PinGroups {
+        g1: Some(g1.pin_group),
+        g2: Some(g2.pin_group),
+        g3: Some(g3.pin_group),
+        ..Default::default()
+    };
+
+    let mut touch_controller = tsc::Tsc::new_blocking(context.TSC, pin_groups, tsc_conf).unwrap();
+
+    if touch_controller.get_state() != State::Ready {
+        crate::panic!("TSC not ready!");
+    }
+    info!("TSC initialized successfully");
+
+    let mut led = Output::new(context.PA5, Level::High, Speed::Low);
+
+    let scanner = MatrixScanner::<ROWS, COLS>::new([row_0.pin, row_1.pin], [col_0.pin, col_1.pin]);
+
+    info!("scanning keypad");
+    loop {
+        let grid = scanner.scan(&mut touch_controller, 5).await;
+
+        let mut any_touched = false;
+        for (row_idx, row) in grid.iter().enumerate() {
+            for (col_idx, &value) in row.iter().enumerate() {
+                info!("key ({}, {}) value {}", row_idx, col_idx, value);
+                if value < KEY_THRESHOLD {
+                    any_touched = true;
+                }
+            }
+        }
+
+        if any_touched {
+            led.set_high();
+        } else {
+            led.set_low();
+        }
+
+        Timer::after_millis(100).await;
+    }
+}
@@ -0,0 +1,110 @@
+// Example of scanning multiple TSC groups in a single acquisition cycle.
+//
+// This example demonstrates:
+// 1. Configuring channel pins across two TSC groups
+// 2. Starting one acquisition that scans every enabled group concurrently, instead of scanning
+//    channels serially with `set_active_channels_mask` + `start` per channel
+// 3. Reading back every completed group's value in one pass with `read_all_groups`
+//
+// The TSC hardware acquires every enabled group concurrently once started; this example uses
+// that directly via `acquire_all`/`read_all_groups` rather than looping over groups one at a
+// time, which cuts scan time roughly in half for a two-pad design.
+//
+// Suggested physical setup on STM32L073RZ Nucleo board:
+// - Connect a 1000pF capacitor between pin PA0 and GND, and one between PB0 and GND. These are
+//   your sampling capacitors.
+// - Connect one end of a 1K resistor to pin PA1 (touch sensor 1) and another to PB1
+//   (touch sensor 2), leaving the other ends loose.
+//
+// The example uses:
+// - PA0 as the sampling capacitor, TSC group 1 IO1 (label A0)
+// - PA1 as the channel pin, TSC group 1 IO2 (label A1)
+// - PB0 as the sampling capacitor, TSC group 2 IO1 (label D3)
+// - PB1 as the channel pin, TSC group 2 IO2 (label D6)
+
+#![no_std]
+#![no_main]
+
+use defmt::*;
+use embassy_stm32::gpio::{Level, Output, Speed};
+use embassy_stm32::peripherals;
+use embassy_stm32::tsc::{self, *};
+use embassy_time::Timer;
+use {defmt_rtt as _, panic_probe as _};
+
+const SENSOR_THRESHOLD: u16 = 25;
+
+#[embassy_executor::main]
+async fn main(_spawner: embassy_executor::Spawner) {
+    let device_config = embassy_stm32::Config::default();
+    let context = embassy_stm32::init(device_config);
+
+    let tsc_conf = Config {
+        ct_pulse_high_length: ChargeTransferPulseCycle::_4,
+        ct_pulse_low_length: ChargeTransferPulseCycle::_4,
+        spread_spectrum: false,
+        spread_spectrum_deviation: SSDeviation::new(2).unwrap(),
+        spread_spectrum_prescaler: false,
+        pulse_generator_prescaler: PGPrescalerDivider::_16,
+        max_count_value: MaxCount::_255,
+        io_default_mode: false,
+        synchro_pin_polarity: false,
+        acquisition_mode: false,
+        max_count_interrupt: false,
+    };
+
+    let mut g1: PinGroupWithRoles<peripherals::TSC, G1> = PinGroupWithRoles::default();
+    g1.set_io1::<tsc::pin_roles::Sample>(context.PA0);
+    let sensor_1 = g1.set_io2::<tsc::pin_roles::Channel>(context.PA1);
+
+    let mut g2: PinGroupWithRoles<peripherals::TSC, G2> = PinGroupWithRoles::default();
+    g2.set_io1::<tsc::pin_roles::Sample>(context.PB0);
+    let sensor_2 = g2.set_io2::<tsc::pin_roles::Channel>(context.PB1);
+
+    let pin_groups: PinGroups<peripherals::TSC> = PinGroups {
+        g1: Some(g1.pin_group),
+        g2: Some(g2.pin_group),
+        ..Default::default()
+    };
+
+    let mut touch_controller = tsc::Tsc::new_blocking(context.TSC, pin_groups, tsc_conf).unwrap();
+
+    if touch_controller.get_state() != State::Ready {
+        crate::panic!("TSC not ready!");
+    }
+    info!("TSC initialized successfully");
+
+    let mut led = Output::new(context.PA5, Level::High, Speed::Low);
+
+    let discharge_delay = 5; // ms
+    let polling_interval = 100; // ms
+
+    // Enable every channel pin across both groups up front; `acquire_all` scans every enabled
+    // group in a single acquisition cycle regardless of how many channels are active.
+    touch_controller.set_active_channels_mask(ChannelMask::from(sensor_1.pin) | ChannelMask::from(sensor_2.pin));
+
+    info!("polling for touch");
+    loop {
+        // `acquire_all` blocks until the whole cycle completes, so it's safe to discharge
+        // immediately afterwards, same as `start()` + `poll_for_acquisition()` elsewhere.
+        touch_controller.acquire_all();
+        touch_controller.discharge_io(true);
+        Timer::after_millis(discharge_delay).await;
+
+        let mut any_touched = false;
+        for (group, value) in touch_controller.read_all_groups() {
+            info!("group {} value {}", group, value);
+            if value < SENSOR_THRESHOLD {
+                any_touched = true;
+            }
+        }
+
+        if any_touched {
+            led.set_high();
+        } else {
+            led.set_low();
+        }
+
+        Timer::after_millis(polling_interval).await;
+    }
+}